@@ -1,4 +1,6 @@
 import dgram from 'node:dgram';
+import { lookup } from 'node:dns/promises';
+import { isIP } from 'node:net';
 import { URL } from 'node:url';
 import { Config, Connection, H3Config, H3Connection, generateCid, nwepAlpn, PROTOCOL_VERSION, } from '@webprotocol/nwep';
 import { isNapiError } from './napi-helpers.js';
@@ -14,7 +16,14 @@ export async function fetch(url, options = {}) {
     const port = parsedUrl.port ? parseInt(parsedUrl.port) : 443;
     const path = parsedUrl.pathname + parsedUrl.search;
     const method = options.method || 'READ';
-    const isIPv6 = host.includes(':');
+    const deadline = Date.now() + 30000;
+    // resolve hostnames up front so quiche and the socket always see a literal address
+    const ipFamily = isIP(host);
+    const { address, family } = ipFamily
+        ? { address: host, family: ipFamily }
+        : await resolveHost(host, deadline - Date.now());
+    const isIPv6 = family === 6;
+    const authority = host.includes(':') ? `[${host}]:${port}` : `${host}:${port}`;
     const socketType = isIPv6 ? 'udp6' : 'udp4';
     const socket = dgram.createSocket(socketType);
     return new Promise((resolve, reject) => {
@@ -55,7 +64,7 @@ export async function fetch(url, options = {}) {
                     const headers = [
                         { name: Buffer.from(':method'), value: Buffer.from(method) },
                         { name: Buffer.from(':scheme'), value: Buffer.from('web') },
-                        { name: Buffer.from(':authority'), value: Buffer.from(isIPv6 ? `[${host}]:${port}` : `${host}:${port}`) },
+                        { name: Buffer.from(':authority'), value: Buffer.from(authority) },
                         { name: Buffer.from(':path'), value: Buffer.from(path) },
                         { name: Buffer.from('user-agent'), value: Buffer.from('webfetch/1.0') },
                     ];
@@ -167,7 +176,7 @@ export async function fetch(url, options = {}) {
                 }
                 if (len === null || len === undefined)
                     break;
-                socket.send(buf.subarray(0, len), port, host);
+                socket.send(buf.subarray(0, len), port, address);
             }
         }
         function setupTimeoutHandler() {
@@ -239,7 +248,7 @@ export async function fetch(url, options = {}) {
             }
             const scid = scidResult;
             const local = isIPv6 ? '[::]:0' : '0.0.0.0:0';
-            const peer = isIPv6 ? `[${host}]:${port}` : `${host}:${port}`;
+            const peer = isIPv6 ? `[${address}]:${port}` : `${address}:${port}`;
             const connResult = Connection.connect(scid, local, peer, config);
             if (isNapiError(connResult)) {
                 throw new Error(`Failed to connect: ${connResult.message}`);
@@ -265,9 +274,24 @@ export async function fetch(url, options = {}) {
                 cleanup();
                 reject(new Error('Request timeout'));
             }
-        }, 30000);
+        }, deadline - Date.now());
     });
 }
+async function resolveHost(host, timeoutMs) {
+    let timer;
+    const resolved = lookup(host).catch((err) => {
+        throw new Error(`Failed to resolve ${host}: ${err.message}`, { cause: err });
+    });
+    const timedOut = new Promise((_, reject) => {
+        timer = setTimeout(() => reject(new Error('Request timeout')), timeoutMs);
+    });
+    try {
+        return await Promise.race([resolved, timedOut]);
+    }
+    finally {
+        clearTimeout(timer);
+    }
+}
 function getStatusText(status) {
     const statusTexts = {
         'ok': 'OK',
@@ -1,4 +1,7 @@
 import dgram from 'node:dgram';
+import type { LookupAddress } from 'node:dns';
+import { lookup } from 'node:dns/promises';
+import { isIP } from 'node:net';
 import { URL } from 'node:url';
 import {
   Config,
@@ -45,8 +48,16 @@ export async function fetch(url: string, options: FetchOptions = {}): Promise<Fe
   const port = parsedUrl.port ? parseInt(parsedUrl.port) : 443;
   const path = parsedUrl.pathname + parsedUrl.search;
   const method = options.method || 'READ';
+  const deadline = Date.now() + 30000;
 
-  const isIPv6 = host.includes(':');
+  // resolve hostnames up front so quiche and the socket always see a literal address
+  const ipFamily = isIP(host);
+  const { address, family } = ipFamily
+    ? { address: host, family: ipFamily }
+    : await resolveHost(host, deadline - Date.now());
+
+  const isIPv6 = family === 6;
+  const authority = host.includes(':') ? `[${host}]:${port}` : `${host}:${port}`;
 
   const socketType = isIPv6 ? 'udp6' : 'udp4';
   const socket = dgram.createSocket(socketType);
@@ -96,7 +107,7 @@ export async function fetch(url: string, options: FetchOptions = {}): Promise<Fe
           const headers: Header[] = [
             { name: Buffer.from(':method'), value: Buffer.from(method) },
             { name: Buffer.from(':scheme'), value: Buffer.from('web') },
-            { name: Buffer.from(':authority'), value: Buffer.from(isIPv6 ? `[${host}]:${port}` : `${host}:${port}`) },
+            { name: Buffer.from(':authority'), value: Buffer.from(authority) },
             { name: Buffer.from(':path'), value: Buffer.from(path) },
             { name: Buffer.from('user-agent'), value: Buffer.from('webfetch/1.0') },
           ];
@@ -228,7 +239,7 @@ export async function fetch(url: string, options: FetchOptions = {}): Promise<Fe
 
         if (len === null || len === undefined) break;
 
-        socket.send(buf.subarray(0, len), port, host);
+        socket.send(buf.subarray(0, len), port, address);
       }
     }
 
@@ -311,7 +322,7 @@ export async function fetch(url: string, options: FetchOptions = {}): Promise<Fe
       const scid = scidResult;
 
       const local = isIPv6 ? '[::]:0' : '0.0.0.0:0';
-      const peer = isIPv6 ? `[${host}]:${port}` : `${host}:${port}`;
+      const peer = isIPv6 ? `[${address}]:${port}` : `${address}:${port}`;
 
       const connResult = Connection.connect(scid, local, peer, config);
       if (isNapiError(connResult)) {
@@ -341,8 +352,25 @@ export async function fetch(url: string, options: FetchOptions = {}): Promise<Fe
         cleanup();
         reject(new Error('Request timeout'));
       }
-    }, 30000);
+    }, deadline - Date.now());
+  });
+}
+
+async function resolveHost(host: string, timeoutMs: number): Promise<LookupAddress> {
+  let timer: NodeJS.Timeout | undefined;
+
+  const resolved = lookup(host).catch((err: Error) => {
+    throw new Error(`Failed to resolve ${host}: ${err.message}`, { cause: err });
+  });
+  const timedOut = new Promise<never>((_, reject) => {
+    timer = setTimeout(() => reject(new Error('Request timeout')), timeoutMs);
   });
+
+  try {
+    return await Promise.race([resolved, timedOut]);
+  } finally {
+    clearTimeout(timer);
+  }
 }
 
 function getStatusText(status: string): string {